@@ -5,27 +5,39 @@
 //!
 //! File watching is not built in, but can be simulated with the `watch` program or similar.
 //!
+//! Reading ([`SeekableRead`], [`BinaryEntry`], head/tail) depends only on `core`/`alloc`, so it
+//! runs on bare block devices and firmware images. Writing ([`BinaryChunkStream`]) needs a real
+//! `Write` implementation, so it stays behind the default `std` feature; disable default features
+//! to build without `std`.
+//!
 //! [`BinaryEntry`]: trait.BinaryEntry.html
+//! [`SeekableRead`]: trait.SeekableRead.html
+//! [`BinaryChunkStream`]: trait.BinaryChunkStream.html
 //!
 //! # Examples
 //!
+//! With the default `std` feature, entries can be written and read back from a real file:
+//!
 //! ```
+//! # #[cfg(feature = "std")]
+//! # fn main() {
 //! use std::fs::File;
-//! use std::io::{ Result };
-//! use self::skullrump::byteorder::{ WriteBytesExt, ReadBytesExt };
-//! use self::skullrump::{ BinaryEntry, BinaryChunkStream };
+//! use self::skullrump::byteorder::{ ByteOrder, LittleEndian };
+//! use self::skullrump::{ BinaryEntry, BinaryChunkStream, SeekableRead, Result };
 //!
 //! struct ASingleByte(u8);
 //!
 //! impl BinaryEntry for ASingleByte {
-//!  fn entry_write(data_in: Self, buffer_out: &mut Vec<u8>) -> Result<()> {
-//!    buffer_out.write_u8(data_in.0)
+//!  fn entry_write<B: ByteOrder>(data_in: Self, buffer_out: &mut Vec<u8>) -> Result<()> {
+//!    buffer_out.push(data_in.0);
+//!    Ok(())
 //!  }
 //!
-//!  fn entry_read(file: &mut File) -> Result<Self> {
-//!    file
-//!      .read_u8()
-//!      .and_then(|data| Ok(ASingleByte(data)))
+//!  fn entry_read<B: ByteOrder, S: SeekableRead>(stream: &mut S) -> Result<Self> {
+//!    stream.ensure_not_eof()?;
+//!    let mut buf = [0u8; 1];
+//!    stream.read_exact(&mut buf)?;
+//!    Ok(ASingleByte(buf[0]))
 //!  }
 //!
 //!  fn entry_size() -> i64 {
@@ -36,58 +48,125 @@
 //! fn foo(file: &mut File) {
 //!   let mut buff:Vec<u8> = vec![];
 //!
-//!   file.entry_write(&mut buff, ASingleByte(1));
-//!   match file.tail::<ASingleByte>(1) {
+//!   file.entry_write::<ASingleByte, LittleEndian>(&mut buff, ASingleByte(1));
+//!   match file.tail::<ASingleByte, LittleEndian>(1) {
 //!     Ok(_entries) => {}
 //!     Err(_)      => {}
 //!   };
 //! }
+//! # }
+//! # #[cfg(not(feature = "std"))]
+//! # fn main() {}
 //! ```
 //!
+//! Without `std`, the same parsing logic runs against a borrowed byte slice via [`SliceCursor`]
+//! instead of a file:
+//!
+//! ```
+//! use self::skullrump::byteorder::{ ByteOrder, LittleEndian };
+//! use self::skullrump::{ BinaryEntry, SeekableRead, SliceCursor, Result };
+//!
+//! struct ASingleByte(u8);
+//!
+//! impl BinaryEntry for ASingleByte {
+//!  fn entry_write<B: ByteOrder>(_data_in: Self, _buffer_out: &mut Vec<u8>) -> Result<()> {
+//!    Ok(())
+//!  }
+//!
+//!  fn entry_read<B: ByteOrder, S: SeekableRead>(stream: &mut S) -> Result<Self> {
+//!    stream.ensure_not_eof()?;
+//!    let mut buf = [0u8; 1];
+//!    stream.read_exact(&mut buf)?;
+//!    Ok(ASingleByte(buf[0]))
+//!  }
+//!
+//!  fn entry_size() -> i64 {
+//!    1
+//!  }
+//! }
+//!
+//! fn foo(data: &[u8]) {
+//!   let mut stream = SliceCursor::new(data);
+//!
+//!   match stream.tail::<ASingleByte, LittleEndian>(1) {
+//!     Ok(_entries) => {}
+//!     Err(_)      => {}
+//!   };
+//! }
+//! ```
+//!
+//! [`SliceCursor`]: struct.SliceCursor.html
+//!
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
+extern crate core;
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
 
 mod skullrump;
 pub extern crate byteorder;
 
+#[cfg(feature = "std")]
+pub use skullrump::BinaryChunkStream;
+
 pub use skullrump::{
   BinaryEntry,
-  BinaryChunkStream,
+  Result,
+  SeekFrom,
+  SeekableRead,
+  SkullrumpError,
+  SliceCursor,
   StreamFlow
 };
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
   extern crate byteorder;
 
-  use std::fs::{ 
+  use std::fs::{
     File,
     OpenOptions
   };
 
   use self::byteorder::{
-    LittleEndian,
-    ReadBytesExt
+    ByteOrder,
+    LittleEndian
   };
 
   use std::io::{
-    Result,
+    Cursor,
     Write
   };
 
   use skullrump::{
     BinaryChunkStream,
-    BinaryEntry
+    BinaryEntry,
+    Result,
+    SeekableRead,
+    SkullrumpError,
+    SliceCursor
   };
 
   struct CustomType(i64, f32);
 
   impl BinaryEntry for CustomType {
-    fn entry_write(_data_in: Self, _buffer_out: &mut Vec<u8>) -> Result<()> {
+    fn entry_write<B: ByteOrder>(_data_in: Self, _buffer_out: &mut Vec<u8>) -> Result<()> {
       Ok(())
     }
 
-    fn entry_read(file: &mut File) -> Result<Self> {
-      let p1 = file.read_i64::<LittleEndian>().or::<Result<i64>>(Ok(0)).unwrap();
-      let p2 = file.read_f32::<LittleEndian>().or::<Result<f32>>(Ok(0.0)).unwrap();
+    fn entry_read<B: ByteOrder, S: SeekableRead>(stream: &mut S) -> Result<Self> {
+      stream.ensure_not_eof()?;
+
+      let mut p1_buf = [0u8; 8];
+      stream.read_exact(&mut p1_buf)?;
+      let p1 = B::read_i64(&p1_buf);
+
+      let mut p2_buf = [0u8; 4];
+      stream.read_exact(&mut p2_buf)?;
+      let p2 = B::read_f32(&p2_buf);
 
       return Ok(CustomType(p1, p2));
     }
@@ -97,6 +176,38 @@ mod tests {
     }
   }
 
+  struct VarString(String);
+
+  impl BinaryEntry for VarString {
+    const FIXED_SIZE: bool = false;
+
+    fn entry_write<B: ByteOrder>(data_in: Self, buffer_out: &mut Vec<u8>) -> Result<()> {
+      let bytes = data_in.0.into_bytes();
+      buffer_out.push(bytes.len() as u8);
+      buffer_out.extend_from_slice(&bytes);
+      Ok(())
+    }
+
+    fn entry_read<B: ByteOrder, S: SeekableRead>(stream: &mut S) -> Result<Self> {
+      stream.ensure_not_eof()?;
+
+      let mut len_buf = [0u8; 1];
+      stream.read_exact(&mut len_buf)?;
+
+      let mut bytes = vec![0u8; len_buf[0] as usize];
+      stream.read_exact(&mut bytes)?;
+
+      match String::from_utf8(bytes) {
+        Ok(s)   => Ok(VarString(s)),
+        Err(_) => Err(SkullrumpError::WrongRange)
+      }
+    }
+
+    fn entry_size() -> i64 {
+      0
+    }
+  }
+
   #[test]
   fn read_no_entries_forward() {
     let mut file = OpenOptions
@@ -110,7 +221,7 @@ mod tests {
 
     file.write_all(&[2u8,0u8,0u8,0u8,0u8,0u8,0u8,0u8]).unwrap();
     file.flush().unwrap();
-    assert_eq!(true, file.head::<i64>(0).unwrap().is_empty());
+    assert_eq!(true, file.head::<i64, LittleEndian>(0).unwrap().is_empty());
   }
 
   #[test]
@@ -125,8 +236,8 @@ mod tests {
 
     file.write_all(&[1u8,0u8,0u8,0u8,0u8,0u8,0u8,0u8,2u8,0u8,0u8,0u8,0u8,0u8,0u8,0u8]).unwrap();
     file.flush().unwrap();
-    
-    assert_eq!(true, file.head::<i64>(-2).unwrap().is_empty());
+
+    assert_eq!(true, file.head::<i64, LittleEndian>(-2).unwrap().is_empty());
   }
 
   #[test]
@@ -142,8 +253,8 @@ mod tests {
     file.write_all(&[1u8,0u8,0u8,0u8,0u8,0u8,0u8,0u8]).unwrap();
     file.write_all(&[2u8,0u8,0u8,0u8,0u8,0u8,0u8,0u8]).unwrap();
     file.flush().unwrap();
-    
-    let result = file.head::<i64>(2).unwrap();
+
+    let result = file.head::<i64, LittleEndian>(2).unwrap();
     assert_eq!(1i64, *(result.get(0).unwrap()));
     assert_eq!(2i64, *(result.get(1).unwrap()));
   }
@@ -161,8 +272,8 @@ mod tests {
     file.write_all(&[1u8,0u8,0u8,0u8,0u8,0u8,0u8,0u8]).unwrap();
     file.write_all(&[2u8,0u8,0u8,0u8,0u8,0u8,0u8,0u8]).unwrap();
     file.flush().unwrap();
-    
-    let result = file.head::<i64>(3).unwrap();
+
+    let result = file.head::<i64, LittleEndian>(3).unwrap();
     assert_eq!(1i64, *(result.get(0).unwrap()));
     assert_eq!(2i64, *(result.get(1).unwrap()));
   }
@@ -179,8 +290,8 @@ mod tests {
 
     file.write_all(&[1u8,0u8,0u8,0u8,0u8,0u8,0u8,0u8,2u8,0u8,0u8,0u8,0u8,0u8,0u8,0u8]).unwrap();
     file.flush().unwrap();
-    
-    assert_eq!(true, file.tail::<i64>(0).unwrap().is_empty());
+
+    assert_eq!(true, file.tail::<i64, LittleEndian>(0).unwrap().is_empty());
   }
 
   #[test]
@@ -195,8 +306,8 @@ mod tests {
 
     file.write_all(&[1u8,0u8,0u8,0u8,0u8,0u8,0u8,0u8,2u8,0u8,0u8,0u8,0u8,0u8,0u8,0u8]).unwrap();
     file.flush().unwrap();
-    
-    let result = file.tail::<i64>(2).unwrap();
+
+    let result = file.tail::<i64, LittleEndian>(2).unwrap();
     assert_eq!(1i64, *(result.get(0).unwrap()));
     assert_eq!(2i64, *(result.get(1).unwrap()));
   }
@@ -213,8 +324,8 @@ mod tests {
 
     file.write_all(&[1u8,0u8,0u8,0u8,0u8,0u8,0u8,0u8,2u8,0u8,0u8,0u8,0u8,0u8,0u8,0u8]).unwrap();
     file.flush().unwrap();
-    
-    assert_eq!(true, file.tail::<i64>(-2).unwrap().is_empty());
+
+    assert_eq!(true, file.tail::<i64, LittleEndian>(-2).unwrap().is_empty());
   }
 
   #[test]
@@ -229,9 +340,101 @@ mod tests {
 
     file.write_all(&[1u8,0u8,0u8,0u8,0u8,0u8,0u8,0u8,2u8,0u8,0u8,0u8,0u8,0u8,0u8,0u8]).unwrap();
     file.flush().unwrap();
-    
-    let result = file.tail::<i64>(3).unwrap();
+
+    let result = file.tail::<i64, LittleEndian>(3).unwrap();
     assert_eq!(1i64, *(result.get(0).unwrap()));
     assert_eq!(2i64, *(result.get(1).unwrap()));
   }
+
+  #[test]
+  fn read_n_entries_forward_variable_length() {
+    let mut stream = Cursor::new(Vec::<u8>::new());
+    let mut buff: Vec<u8> = vec![];
+
+    stream.entry_write::<VarString, LittleEndian>(&mut buff, VarString("hi".to_string())).unwrap();
+    stream.entry_write::<VarString, LittleEndian>(&mut buff, VarString("world".to_string())).unwrap();
+
+    let result = stream.head::<VarString, LittleEndian>(2).unwrap();
+    assert_eq!("hi", result.get(0).unwrap().0);
+    assert_eq!("world", result.get(1).unwrap().0);
+  }
+
+  #[test]
+  fn read_n_entries_backward_variable_length() {
+    let mut stream = Cursor::new(Vec::<u8>::new());
+    let mut buff: Vec<u8> = vec![];
+
+    stream.entry_write::<VarString, LittleEndian>(&mut buff, VarString("hi".to_string())).unwrap();
+    stream.entry_write::<VarString, LittleEndian>(&mut buff, VarString("world".to_string())).unwrap();
+
+    let result = stream.tail::<VarString, LittleEndian>(2).unwrap();
+    assert_eq!("hi", result.get(0).unwrap().0);
+    assert_eq!("world", result.get(1).unwrap().0);
+  }
+
+  #[test]
+  fn read_n_entries_forward_via_slice_cursor() {
+    let mut buffer: Vec<u8> = vec![];
+    i64::entry_write::<LittleEndian>(1, &mut buffer).unwrap();
+    i64::entry_write::<LittleEndian>(2, &mut buffer).unwrap();
+
+    let mut stream = SliceCursor::new(&buffer);
+
+    let result = stream.head::<i64, LittleEndian>(2).unwrap();
+    assert_eq!(1i64, *(result.get(0).unwrap()));
+    assert_eq!(2i64, *(result.get(1).unwrap()));
+  }
+
+  #[test]
+  fn read_n_entries_with_a_buffer_size_smaller_than_all_entries() {
+    let mut file = OpenOptions
+      ::new()
+      .write(true)
+      .read(true)
+      .create(true)
+      .open("test.bin")
+      .unwrap();
+
+    file.write_all(&[1u8,0u8,0u8,0u8,0u8,0u8,0u8,0u8]).unwrap();
+    file.write_all(&[2u8,0u8,0u8,0u8,0u8,0u8,0u8,0u8]).unwrap();
+    file.write_all(&[3u8,0u8,0u8,0u8,0u8,0u8,0u8,0u8]).unwrap();
+    file.flush().unwrap();
+
+    // One entry is 8 bytes, so a buffer of 8 bytes forces a refill between every entry.
+    let result = file.head_with_buffer_size::<i64, LittleEndian>(3, 8).unwrap();
+    assert_eq!(1i64, *(result.get(0).unwrap()));
+    assert_eq!(2i64, *(result.get(1).unwrap()));
+    assert_eq!(3i64, *(result.get(2).unwrap()));
+
+    let result = file.tail_with_buffer_size::<i64, LittleEndian>(2, 8).unwrap();
+    assert_eq!(2i64, *(result.get(0).unwrap()));
+    assert_eq!(3i64, *(result.get(1).unwrap()));
+  }
+
+  #[test]
+  fn read_past_a_truncated_trailing_entry_errors() {
+    let mut file = OpenOptions
+      ::new()
+      .write(true)
+      .read(true)
+      .create(true)
+      .truncate(true)
+      .open("test.bin")
+      .unwrap();
+
+    // One whole 8-byte entry, then 4 trailing bytes: not enough for a second entry.
+    file.write_all(&[1u8,0u8,0u8,0u8,0u8,0u8,0u8,0u8,0u8,0u8,0u8,0u8]).unwrap();
+    file.flush().unwrap();
+
+    // Asking for only the whole entry on hand doesn't touch the truncated tail.
+    let result = file.head::<i64, LittleEndian>(1).unwrap();
+    assert_eq!(1i64, *(result.get(0).unwrap()));
+
+    // Asking for more than that means reading into the truncated tail, which should error
+    // rather than silently return just the one whole entry.
+    match file.head::<i64, LittleEndian>(2) {
+      Err(SkullrumpError::ReadError(_)) => {}
+      other => panic!("expected a ReadError, got {:?}", other)
+    }
+  }
 }
@@ -1,19 +1,25 @@
 extern crate byteorder;
 
-use std::fs::{
-  File
-};
-use std::io::{
-  Write,
-  Result,
-  Seek,
-  SeekFrom
-};
-use byteorder::{
-  LittleEndian,
-  ReadBytesExt,
-  WriteBytesExt
-};
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+use std::error::Error as StdError;
+#[cfg(feature = "std")]
+use std::fmt;
+#[cfg(feature = "std")]
+use std::fs::File;
+#[cfg(feature = "std")]
+use std::io;
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use byteorder::ByteOrder;
 
 #[allow(dead_code)]
 /// The direction the stream is reading in.
@@ -22,104 +28,552 @@ pub enum StreamFlow {
   Backward
 }
 
-/// A single entry to be written to a binary file.
-pub trait BinaryEntry: Sized {
-  /// Write an entry to a reusable output buffer.
-  fn entry_write(data_in: Self, buffer_out: &mut Vec<u8>) -> Result<()>;
-
-  /// Read an entry from a file.
-  fn entry_read(file: &mut File) -> Result<Self>;
-
-  /// Get the size of an entry.
-  ///
-  /// Entries can vary in complexity, so it's necessary to implement this rather than magically calculate it.
-  fn entry_size() -> i64;
+/// A seek origin, mirroring `std::io::SeekFrom` without requiring `std`.
+#[derive(Clone, Copy, Debug)]
+pub enum SeekFrom {
+  Start(u64),
+  Current(i64),
+  End(i64)
 }
 
-/// A "stream" of incoming binary entries.
-pub trait BinaryChunkStream: Write {
-  /// Write a new binary entry from an output buffer to a file.
-  fn entry_write<T: BinaryEntry>(&mut self, buffer_out: &mut Vec<u8>, data_in: T) -> Result<()> {
-    T::entry_write(data_in, buffer_out)
-      .and(self.write_all(&buffer_out))
-      .and(self.flush())
-      .and(Ok(buffer_out.clear()))
+#[cfg(feature = "std")]
+impl From<SeekFrom> for io::SeekFrom {
+  fn from(pos: SeekFrom) -> io::SeekFrom {
+    match pos {
+      SeekFrom::Start(n) => io::SeekFrom::Start(n),
+      SeekFrom::Current(n) => io::SeekFrom::Current(n),
+      SeekFrom::End(n) => io::SeekFrom::End(n)
+    }
   }
+}
 
-  /// Specify the reading direction and number of entries to read, and return the list of entries.
-  fn stream_in<T: BinaryEntry>(&mut self, direction: StreamFlow, until_entry: i64) -> Result<Vec<T>>;
+/// An error produced while reading entries out of a [`SeekableRead`] stream.
+///
+/// The `std`-backed variants carry the underlying [`std::io::Error`]; without the `std`
+/// feature there's no `std::io::Error` to carry, so the variants are bare.
+///
+/// [`SeekableRead`]: trait.SeekableRead.html
+/// [`std::io::Error`]: https://doc.rust-lang.org/std/io/struct.Error.html
+#[derive(Debug)]
+pub enum SkullrumpError {
+  /// The stream had no more entries left; a clean, expected end of stream.
+  Eof,
+  /// A seek landed outside the bounds of the stream, or a fixed-size entry type reported an
+  /// `entry_size()` of zero, so no entry offsets could be computed from it.
+  WrongRange,
+  /// The stream had data but not enough of it to decode a whole entry: a truncated or corrupt record.
+  #[cfg(feature = "std")]
+  ReadError(io::Error),
+  #[cfg(not(feature = "std"))]
+  ReadError,
+  /// The underlying seek operation itself failed.
+  #[cfg(feature = "std")]
+  SeekError(io::Error),
+  #[cfg(not(feature = "std"))]
+  SeekError
+}
 
-  /// Read from the end of the file `until_entry` is reached.
-  fn tail<T: BinaryEntry>(&mut self, until_entry: i64) -> Result<Vec<T>> {
-    self.stream_in(StreamFlow::Backward, until_entry)
+#[cfg(feature = "std")]
+impl fmt::Display for SkullrumpError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      SkullrumpError::Eof => write!(f, "end of stream"),
+      SkullrumpError::WrongRange => write!(f, "seek landed outside the bounds of the stream, or entry size is zero"),
+      SkullrumpError::ReadError(ref e) => write!(f, "failed to read a whole entry: {}", e),
+      SkullrumpError::SeekError(ref e) => write!(f, "failed to seek: {}", e)
+    }
   }
+}
 
-  /// Read from the start of the file `until_entry` is reached.
-  fn head<T: BinaryEntry>(&mut self, until_entry: i64) -> Result<Vec<T>> {
-    self.stream_in(StreamFlow::Forward, until_entry)
+#[cfg(feature = "std")]
+impl StdError for SkullrumpError {
+  fn source(&self) -> Option<&(dyn StdError + 'static)> {
+    match *self {
+      SkullrumpError::ReadError(ref e) | SkullrumpError::SeekError(ref e) => Some(e),
+      _ => None
+    }
   }
 }
 
-impl BinaryEntry for i64 {
-  fn entry_write(data_in: Self, buffer_out: &mut Vec<u8>) -> Result<()> { buffer_out.write_i64::<LittleEndian>(data_in) }
-  fn entry_read(file: &mut File) -> Result<Self> { file.read_i64::<LittleEndian>() }
-  fn entry_size() -> i64 { ::std::mem::size_of::<Self>() as i64 }
+#[cfg(feature = "std")]
+impl From<io::Error> for SkullrumpError {
+  fn from(error: io::Error) -> Self {
+    SkullrumpError::ReadError(error)
+  }
 }
 
-impl BinaryEntry for f32 {
-  fn entry_write(data_in: Self, buffer_out: &mut Vec<u8>) -> Result<()> { buffer_out.write_f32::<LittleEndian>(data_in) }
-  fn entry_read(file: &mut File) -> Result<Self> { file.read_f32::<LittleEndian>() }
-  fn entry_size() -> i64 { ::std::mem::size_of::<Self>() as i64 }
-}
+/// The result of an entry-reading operation, carrying a [`SkullrumpError`] on failure.
+///
+/// [`SkullrumpError`]: enum.SkullrumpError.html
+pub type Result<T> = ::core::result::Result<T, SkullrumpError>;
+
+/// A readable, seekable binary backend that entries can be parsed out of.
+///
+/// This depends only on `core`/`alloc`, so it can be implemented for bare block devices and
+/// firmware images with no filesystem backing them. With the default `std` feature enabled,
+/// it's implemented for `File` and `Cursor<Vec<u8>>` / `Cursor<&[u8]>` so head/tail can also
+/// run against real files or in memory. Without `std`, [`SliceCursor`] provides the in-memory
+/// backend instead.
+///
+/// There's no impl directly on a bare `&[u8]`; wrap it in `Cursor::new(slice)` (with `std`) or
+/// [`SliceCursor::new`] (without) first.
+///
+/// [`SliceCursor`]: struct.SliceCursor.html
+/// [`SliceCursor::new`]: struct.SliceCursor.html#method.new
+pub trait SeekableRead {
+  /// Fill `buf` completely from the stream, advancing its position by `buf.len()` bytes.
+  fn read_exact(&mut self, buf: &mut [u8]) -> Result<()>;
+
+  /// Move the stream's position and return the new absolute position, in bytes from the start.
+  fn seek(&mut self, pos: SeekFrom) -> Result<u64>;
+
+  /// Get the stream's current position, in bytes from the start.
+  fn tell(&mut self) -> Result<u64> {
+    self.seek(SeekFrom::Current(0))
+  }
 
-impl BinaryChunkStream for File {
-  fn stream_in<T: BinaryEntry>(&mut self, direction: StreamFlow, until_entry: i64) -> Result<Vec<T>> {
-    let mut entries: Vec<T> = vec![];
-    let mut entry_index:i64 = 0;
+  /// Get the total length of the stream, in bytes, without disturbing the current position.
+  fn stream_len(&mut self) -> Result<u64> {
+    let current = self.tell()?;
+    let end = self.seek(SeekFrom::End(0))?;
+    self.seek(SeekFrom::Start(current))?;
+    Ok(end)
+  }
 
+  /// Check whether the stream is sitting exactly at its end, with no partial entry straddling it.
+  ///
+  /// `BinaryEntry::entry_read` impls should call this before decoding a new entry so a clean
+  /// end-of-stream can be told apart from a truncated record: the latter trips a read error
+  /// partway through decoding and should propagate as [`SkullrumpError::ReadError`], not be
+  /// swallowed as [`SkullrumpError::Eof`].
+  ///
+  /// [`SkullrumpError::ReadError`]: enum.SkullrumpError.html#variant.ReadError
+  /// [`SkullrumpError::Eof`]: enum.SkullrumpError.html#variant.Eof
+  fn ensure_not_eof(&mut self) -> Result<()> {
+    let position = self.tell()?;
+    let length = self.stream_len()?;
+
+    if position >= length {
+      Err(SkullrumpError::Eof)
+    } else {
+      Ok(())
+    }
+  }
+
+  /// The number of bytes [`stream_in`] reads from the backend per block when [`stream_in`]
+  /// isn't told otherwise. Override on a backend type if a different default block size suits
+  /// it better.
+  ///
+  /// [`stream_in`]: trait.SeekableRead.html#method.stream_in
+  const DEFAULT_BUFFER_SIZE: usize = 8192;
+
+  /// Specify the reading direction, byte order, and number of entries to read, and return the list of entries.
+  fn stream_in<T: BinaryEntry, B: ByteOrder>(&mut self, direction: StreamFlow, until_entry: i64) -> Result<Vec<T>>
+    where Self: Sized
+  {
+    self.stream_in_buffered::<T, B>(direction, until_entry, Self::DEFAULT_BUFFER_SIZE)
+  }
+
+  /// Like [`stream_in`], but lets the caller pick how many bytes are read from the backend per
+  /// block, instead of the backend's [`DEFAULT_BUFFER_SIZE`].
+  ///
+  /// Fixed-size entries are decoded out of an in-memory block rather than being seeked to and
+  /// read one at a time: `head` fills the block by reading forward from the start, refilling as
+  /// entries are consumed, while `tail` seeks once to the computed start of the window and then
+  /// only ever reads forward through the block. Either way the backend sees one `read` per
+  /// block instead of a `seek`/`read` pair per entry.
+  ///
+  /// [`stream_in`]: trait.SeekableRead.html#method.stream_in
+  /// [`DEFAULT_BUFFER_SIZE`]: trait.SeekableRead.html#associatedconstant.DEFAULT_BUFFER_SIZE
+  fn stream_in_buffered<T: BinaryEntry, B: ByteOrder>(&mut self, direction: StreamFlow, until_entry: i64, buffer_size: usize) -> Result<Vec<T>>
+    where Self: Sized
+  {
     if until_entry <= 0 {
-      return Ok(entries);
+      return Ok(Vec::new());
     }
 
-    let data_size = T::entry_size();
-    let tail_position = data_size * until_entry;
-
-    let internal_direction = match direction {
-      StreamFlow::Forward => { StreamFlow::Forward }
-      StreamFlow::Backward => {
-        if let Err(_) = self.seek(SeekFrom::End(-tail_position)) {
-          StreamFlow::Forward
-        } else {
-          StreamFlow::Backward
-        }
-      }
+    if !T::FIXED_SIZE {
+      return match direction {
+        StreamFlow::Forward  => self.stream_in_variable_forward::<T, B>(until_entry),
+        StreamFlow::Backward => self.stream_in_variable_backward::<T, B>(until_entry)
+      };
+    }
+
+    let data_size = T::entry_size() as u64;
+
+    if data_size == 0 {
+      return Err(SkullrumpError::WrongRange);
+    }
+
+    let stream_length = self.stream_len()?;
+    let available_entries = stream_length / data_size;
+    let entry_count = (until_entry as u64).min(available_entries);
+
+    if entry_count < until_entry as u64 && stream_length % data_size != 0 {
+      // There's a truncated/corrupt entry past the last whole one. The caller asked for more
+      // entries than the whole ones on hand, so satisfying the request means reading into it.
+      #[cfg(feature = "std")]
+      return Err(SkullrumpError::ReadError(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated entry")));
+      #[cfg(not(feature = "std"))]
+      return Err(SkullrumpError::ReadError);
+    }
+
+    let start_position = match direction {
+      StreamFlow::Forward  => 0,
+      StreamFlow::Backward => stream_length.saturating_sub(entry_count * data_size)
     };
 
-    loop {
-      let position = data_size * entry_index;
-      match internal_direction {
-        StreamFlow::Forward  => {
-          self.seek(SeekFrom::Start(position as u64))
-        }
-        StreamFlow::Backward => {
-          self.seek(SeekFrom::End(-(tail_position - position)))
-        }
-      }.unwrap();
-
-      match T::entry_read(self) {
-        Ok(entry) => {
-          entries.push(entry);
-          entry_index += 1;
-        }
-        Err(_) => {
-          break;
-        }
+    self.seek(SeekFrom::Start(start_position))?;
+
+    let entries_per_block = ((buffer_size as u64) / data_size).max(1);
+
+    let mut entries: Vec<T> = Vec::new();
+    let mut block: Vec<u8> = Vec::new();
+    let mut block_cursor: usize = 0;
+
+    while (entries.len() as u64) < entry_count {
+      if block_cursor >= block.len() {
+        let remaining_entries = entry_count - entries.len() as u64;
+        let block_entries = remaining_entries.min(entries_per_block);
+
+        block = vec![0u8; (block_entries * data_size) as usize];
+        self.read_exact(&mut block)?;
+        block_cursor = 0;
       }
 
-      if entry_index >= until_entry { break; }
+      let entry_end = block_cursor + data_size as usize;
+      let mut entry_slice = SliceCursor::new(&block[block_cursor..entry_end]);
+      entries.push(T::entry_read::<B, SliceCursor>(&mut entry_slice)?);
+      block_cursor = entry_end;
     }
 
     return Ok(entries);
   }
+
+  /// Read `until_entry` variable-length entries forward from the start of the stream.
+  ///
+  /// Each entry is read sequentially; there's no offset formula to jump to, so this just
+  /// keeps calling `T::entry_read` from wherever the last one left the stream position.
+  fn stream_in_variable_forward<T: BinaryEntry, B: ByteOrder>(&mut self, until_entry: i64) -> Result<Vec<T>>
+    where Self: Sized
+  {
+    let mut entries: Vec<T> = Vec::new();
+    self.seek(SeekFrom::Start(0))?;
+
+    while (entries.len() as i64) < until_entry {
+      match T::entry_read::<B, Self>(self) {
+        Ok(entry) => entries.push(entry),
+        Err(SkullrumpError::Eof) => break,
+        Err(e) => return Err(e)
+      }
+    }
+
+    Ok(entries)
+  }
+
+  /// Read the last `until_entry` variable-length entries from the stream.
+  ///
+  /// Variable-length records have no fixed offset formula to seek backward with, so the only
+  /// way to find where the last `n` records start is to scan forward through the whole stream
+  /// once, recording each record's start position, then seek back to the start of the last `n`
+  /// and read them. Unlike fixed-size `tail`, this is always an O(n) scan of the entire stream.
+  fn stream_in_variable_backward<T: BinaryEntry, B: ByteOrder>(&mut self, until_entry: i64) -> Result<Vec<T>>
+    where Self: Sized
+  {
+    let mut offsets: Vec<u64> = Vec::new();
+    self.seek(SeekFrom::Start(0))?;
+
+    loop {
+      let position = self.tell()?;
+      match T::entry_read::<B, Self>(self) {
+        Ok(_) => offsets.push(position),
+        Err(SkullrumpError::Eof) => break,
+        Err(e) => return Err(e)
+      }
+    }
+
+    let skip = offsets.len().saturating_sub(until_entry as usize);
+    let mut entries: Vec<T> = Vec::new();
+
+    for &offset in &offsets[skip..] {
+      self.seek(SeekFrom::Start(offset))?;
+      entries.push(T::entry_read::<B, Self>(self)?);
+    }
+
+    Ok(entries)
+  }
+
+  /// Read from the end of the stream until `until_entry` is reached.
+  fn tail<T: BinaryEntry, B: ByteOrder>(&mut self, until_entry: i64) -> Result<Vec<T>>
+    where Self: Sized
+  {
+    self.stream_in::<T, B>(StreamFlow::Backward, until_entry)
+  }
+
+  /// Like [`tail`], but reads the backend in blocks of `buffer_size` bytes instead of
+  /// [`DEFAULT_BUFFER_SIZE`].
+  ///
+  /// [`tail`]: trait.SeekableRead.html#method.tail
+  /// [`DEFAULT_BUFFER_SIZE`]: trait.SeekableRead.html#associatedconstant.DEFAULT_BUFFER_SIZE
+  fn tail_with_buffer_size<T: BinaryEntry, B: ByteOrder>(&mut self, until_entry: i64, buffer_size: usize) -> Result<Vec<T>>
+    where Self: Sized
+  {
+    self.stream_in_buffered::<T, B>(StreamFlow::Backward, until_entry, buffer_size)
+  }
+
+  /// Read from the start of the stream until `until_entry` is reached.
+  fn head<T: BinaryEntry, B: ByteOrder>(&mut self, until_entry: i64) -> Result<Vec<T>>
+    where Self: Sized
+  {
+    self.stream_in::<T, B>(StreamFlow::Forward, until_entry)
+  }
+
+  /// Like [`head`], but reads the backend in blocks of `buffer_size` bytes instead of
+  /// [`DEFAULT_BUFFER_SIZE`].
+  ///
+  /// [`head`]: trait.SeekableRead.html#method.head
+  /// [`DEFAULT_BUFFER_SIZE`]: trait.SeekableRead.html#associatedconstant.DEFAULT_BUFFER_SIZE
+  fn head_with_buffer_size<T: BinaryEntry, B: ByteOrder>(&mut self, until_entry: i64, buffer_size: usize) -> Result<Vec<T>>
+    where Self: Sized
+  {
+    self.stream_in_buffered::<T, B>(StreamFlow::Forward, until_entry, buffer_size)
+  }
+}
+
+#[cfg(feature = "std")]
+impl SeekableRead for File {
+  fn read_exact(&mut self, buf: &mut [u8]) -> Result<()> {
+    io::Read::read_exact(self, buf).map_err(SkullrumpError::ReadError)
+  }
+
+  fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+    io::Seek::seek(self, pos.into()).map_err(SkullrumpError::SeekError)
+  }
+}
+
+#[cfg(feature = "std")]
+impl SeekableRead for io::Cursor<Vec<u8>> {
+  fn read_exact(&mut self, buf: &mut [u8]) -> Result<()> {
+    io::Read::read_exact(self, buf).map_err(SkullrumpError::ReadError)
+  }
+
+  fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+    io::Seek::seek(self, pos.into()).map_err(SkullrumpError::SeekError)
+  }
+}
+
+#[cfg(feature = "std")]
+impl SeekableRead for io::Cursor<&[u8]> {
+  fn read_exact(&mut self, buf: &mut [u8]) -> Result<()> {
+    io::Read::read_exact(self, buf).map_err(SkullrumpError::ReadError)
+  }
+
+  fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+    io::Seek::seek(self, pos.into()).map_err(SkullrumpError::SeekError)
+  }
 }
 
+/// An in-memory [`SeekableRead`] over a borrowed byte slice, usable without `std`.
+///
+/// This is the `no_std` counterpart to `Cursor<&[u8]>`: it needs no filesystem or allocator,
+/// just the slice it borrows, so it also works on bare block devices and firmware images.
+///
+/// [`SeekableRead`]: trait.SeekableRead.html
+pub struct SliceCursor<'a> {
+  data: &'a [u8],
+  position: usize
+}
+
+impl<'a> SliceCursor<'a> {
+  /// Wrap `data` for reading from the start.
+  pub fn new(data: &'a [u8]) -> Self {
+    SliceCursor { data, position: 0 }
+  }
+}
+
+impl<'a> SeekableRead for SliceCursor<'a> {
+  fn read_exact(&mut self, buf: &mut [u8]) -> Result<()> {
+    let end = self.position + buf.len();
+
+    if end > self.data.len() {
+      #[cfg(feature = "std")]
+      return Err(SkullrumpError::ReadError(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated entry")));
+      #[cfg(not(feature = "std"))]
+      return Err(SkullrumpError::ReadError);
+    }
+
+    buf.copy_from_slice(&self.data[self.position..end]);
+    self.position = end;
+    Ok(())
+  }
+
+  fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+    let new_position = match pos {
+      SeekFrom::Start(n) => n as i64,
+      SeekFrom::Current(n) => self.position as i64 + n,
+      SeekFrom::End(n) => self.data.len() as i64 + n
+    };
+
+    if new_position < 0 {
+      return Err(SkullrumpError::WrongRange);
+    }
+
+    self.position = new_position as usize;
+    Ok(self.position as u64)
+  }
+}
+
+/// A single entry to be written to a binary file.
+///
+/// The byte order used to encode/decode an entry's fields is picked by the caller via the
+/// `B: ByteOrder` parameter on [`entry_write`] and [`entry_read`], so the same impl serves
+/// both little- and big-endian formats.
+///
+/// [`entry_write`]: trait.BinaryEntry.html#tymethod.entry_write
+/// [`entry_read`]: trait.BinaryEntry.html#tymethod.entry_read
+pub trait BinaryEntry: Sized {
+  /// Whether every encoded entry occupies exactly `entry_size()` bytes.
+  ///
+  /// Fixed-size entries (the default) let `stream_in` compute where the requested entries start
+  /// from `entry_size()` and the stream's length, then read them forward in blocks.
+  /// Variable-length entries (length-prefixed strings, TLV chunks, ...) should override this
+  /// to `false`; `stream_in` then reads sequentially and discovers each entry's extent from
+  /// the stream's position rather than from `entry_size()`.
+  const FIXED_SIZE: bool = true;
+
+  /// Write an entry to a reusable output buffer, encoding its fields in byte order `B`.
+  fn entry_write<B: ByteOrder>(data_in: Self, buffer_out: &mut Vec<u8>) -> Result<()>;
+
+  /// Read an entry from a seekable stream, decoding its fields in byte order `B`.
+  ///
+  /// Implementations should call [`SeekableRead::ensure_not_eof`] before reading so a clean
+  /// end of stream is reported as [`SkullrumpError::Eof`] rather than a truncated-entry error.
+  ///
+  /// [`SeekableRead::ensure_not_eof`]: trait.SeekableRead.html#method.ensure_not_eof
+  /// [`SkullrumpError::Eof`]: enum.SkullrumpError.html#variant.Eof
+  fn entry_read<B: ByteOrder, S: SeekableRead>(stream: &mut S) -> Result<Self>;
+
+  /// Get the size of an entry.
+  ///
+  /// Entries can vary in complexity, so it's necessary to implement this rather than magically
+  /// calculate it. Variable-length entries (`FIXED_SIZE = false`) aren't read via this value;
+  /// it's ignored by `stream_in` and can return any placeholder, e.g. `0`.
+  fn entry_size() -> i64;
+}
+
+/// A "stream" that binary entries can be written out to.
+///
+/// Writing always goes through a buffered `Write` implementation, so (unlike [`SeekableRead`])
+/// this stays behind the default `std` feature.
+///
+/// [`SeekableRead`]: trait.SeekableRead.html
+#[cfg(feature = "std")]
+pub trait BinaryChunkStream: io::Write {
+  /// Write a new binary entry from an output buffer to a file, encoding it in byte order `B`.
+  fn entry_write<T: BinaryEntry, B: ByteOrder>(&mut self, buffer_out: &mut Vec<u8>, data_in: T) -> io::Result<()> {
+    T::entry_write::<B>(data_in, buffer_out)
+      .map_err(|_| io::Error::other("failed to encode entry"))
+      .and(self.write_all(buffer_out))
+      .and(self.flush())
+      .and({ buffer_out.clear(); Ok(()) })
+  }
+}
+
+#[cfg(feature = "std")]
+impl<S: io::Write> BinaryChunkStream for S {}
+
+impl BinaryEntry for i64 {
+  fn entry_write<B: ByteOrder>(data_in: Self, buffer_out: &mut Vec<u8>) -> Result<()> {
+    let mut buf = [0u8; 8];
+    B::write_i64(&mut buf, data_in);
+    buffer_out.extend_from_slice(&buf);
+    Ok(())
+  }
+  fn entry_read<B: ByteOrder, S: SeekableRead>(stream: &mut S) -> Result<Self> {
+    stream.ensure_not_eof()?;
+    let mut buf = [0u8; 8];
+    stream.read_exact(&mut buf)?;
+    Ok(B::read_i64(&buf))
+  }
+  fn entry_size() -> i64 { 8 }
+}
+
+impl BinaryEntry for i32 {
+  fn entry_write<B: ByteOrder>(data_in: Self, buffer_out: &mut Vec<u8>) -> Result<()> {
+    let mut buf = [0u8; 4];
+    B::write_i32(&mut buf, data_in);
+    buffer_out.extend_from_slice(&buf);
+    Ok(())
+  }
+  fn entry_read<B: ByteOrder, S: SeekableRead>(stream: &mut S) -> Result<Self> {
+    stream.ensure_not_eof()?;
+    let mut buf = [0u8; 4];
+    stream.read_exact(&mut buf)?;
+    Ok(B::read_i32(&buf))
+  }
+  fn entry_size() -> i64 { 4 }
+}
+
+impl BinaryEntry for u16 {
+  fn entry_write<B: ByteOrder>(data_in: Self, buffer_out: &mut Vec<u8>) -> Result<()> {
+    let mut buf = [0u8; 2];
+    B::write_u16(&mut buf, data_in);
+    buffer_out.extend_from_slice(&buf);
+    Ok(())
+  }
+  fn entry_read<B: ByteOrder, S: SeekableRead>(stream: &mut S) -> Result<Self> {
+    stream.ensure_not_eof()?;
+    let mut buf = [0u8; 2];
+    stream.read_exact(&mut buf)?;
+    Ok(B::read_u16(&buf))
+  }
+  fn entry_size() -> i64 { 2 }
+}
+
+impl BinaryEntry for u32 {
+  fn entry_write<B: ByteOrder>(data_in: Self, buffer_out: &mut Vec<u8>) -> Result<()> {
+    let mut buf = [0u8; 4];
+    B::write_u32(&mut buf, data_in);
+    buffer_out.extend_from_slice(&buf);
+    Ok(())
+  }
+  fn entry_read<B: ByteOrder, S: SeekableRead>(stream: &mut S) -> Result<Self> {
+    stream.ensure_not_eof()?;
+    let mut buf = [0u8; 4];
+    stream.read_exact(&mut buf)?;
+    Ok(B::read_u32(&buf))
+  }
+  fn entry_size() -> i64 { 4 }
+}
+
+impl BinaryEntry for f32 {
+  fn entry_write<B: ByteOrder>(data_in: Self, buffer_out: &mut Vec<u8>) -> Result<()> {
+    let mut buf = [0u8; 4];
+    B::write_f32(&mut buf, data_in);
+    buffer_out.extend_from_slice(&buf);
+    Ok(())
+  }
+  fn entry_read<B: ByteOrder, S: SeekableRead>(stream: &mut S) -> Result<Self> {
+    stream.ensure_not_eof()?;
+    let mut buf = [0u8; 4];
+    stream.read_exact(&mut buf)?;
+    Ok(B::read_f32(&buf))
+  }
+  fn entry_size() -> i64 { 4 }
+}
+
+impl BinaryEntry for f64 {
+  fn entry_write<B: ByteOrder>(data_in: Self, buffer_out: &mut Vec<u8>) -> Result<()> {
+    let mut buf = [0u8; 8];
+    B::write_f64(&mut buf, data_in);
+    buffer_out.extend_from_slice(&buf);
+    Ok(())
+  }
+  fn entry_read<B: ByteOrder, S: SeekableRead>(stream: &mut S) -> Result<Self> {
+    stream.ensure_not_eof()?;
+    let mut buf = [0u8; 8];
+    stream.read_exact(&mut buf)?;
+    Ok(B::read_f64(&buf))
+  }
+  fn entry_size() -> i64 { 8 }
+}